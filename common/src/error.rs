@@ -3,6 +3,9 @@ use std::collections::HashMap;
 use std::error;
 use std::fmt;
 
+use serde::ser::{Serialize, Serializer};
+use serde_json::{json, Map, Value};
+
 pub enum UnprocessableCode {
     Exists,
     AuthFailed,
@@ -186,6 +189,68 @@ impl Error {
         self.context.extend(err.context);
         self
     }
+
+    /// Whether the operation that produced this error is worth retrying: pool
+    /// exhaustion and dropped connections are transient, while constraint violations
+    /// and other query-level failures are not.
+    pub fn retryable(&self) -> bool {
+        let is_transient = matches!(self.path.as_str(), "redis" | "db")
+            && matches!(self.code.as_str(), "pool" | "connection");
+        is_transient || self.cause().map_or(false, Error::retryable)
+    }
+
+    /// Renders this error (and its cause chain) as a JSON object suitable for an HTTP
+    /// response body. Any `Application`-kind error (the user-facing top-level error, by
+    /// convention) is rendered in full; `Internal`-kind errors — whether `self` itself or
+    /// a cause — are collapsed behind a generic code unless `verbose` is `true`, so
+    /// DB/Redis details are not leaked to clients while still being loggable.
+    pub fn to_response_json(&self, verbose: bool) -> Value {
+        let mut obj = self.summary_json(verbose);
+
+        let mut causes = Vec::new();
+        let mut current = self.cause();
+        while let Some(err) = current {
+            causes.push(err.summary_json(verbose));
+            current = err.cause();
+        }
+        if !causes.is_empty() {
+            if let Value::Object(ref mut map) = obj {
+                map.insert("causes".to_owned(), Value::Array(causes));
+            }
+        }
+
+        obj
+    }
+
+    fn summary_json(&self, verbose: bool) -> Value {
+        if !verbose && self.kind == ErrorKind::Internal {
+            return json!({
+                "kind": self.kind.to_string(),
+                "code": "internal_error",
+            });
+        }
+
+        let mut obj = Map::new();
+        obj.insert("kind".to_owned(), json!(self.kind.to_string()));
+        obj.insert("path".to_owned(), json!(self.path));
+        obj.insert("code".to_owned(), json!(self.code));
+        if let Some(status) = self.status {
+            obj.insert("status".to_owned(), json!(status));
+        }
+        if let Some(message) = &self.message {
+            obj.insert("message".to_owned(), json!(message));
+        }
+        if self.has_context() {
+            obj.insert("context".to_owned(), json!(self.context));
+        }
+        Value::Object(obj)
+    }
+}
+
+impl Serialize for Error {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.to_response_json(false).serialize(serializer)
+    }
 }
 
 impl fmt::Display for Error {
@@ -211,7 +276,12 @@ impl From<deadpool_redis::PoolError> for Error {
 
 impl From<redis::RedisError> for Error {
     fn from(err: redis::RedisError) -> Self {
-        Error::internal("redis", "unknown").wrap_raw(err)
+        let code = if err.is_connection_dropped() || err.is_timeout() {
+            "connection"
+        } else {
+            "unknown"
+        };
+        Error::internal("redis", code).wrap_raw(err)
     }
 }
 
@@ -219,7 +289,23 @@ impl From<redis::RedisError> for Error {
 use crate::pg::{PgError, PoolError};
 impl From<PgError> for Error {
     fn from(err: PgError) -> Self {
-        Error::internal("db", "operation").wrap_raw(err)
+        let code = if is_transient_pg_error(&err) {
+            "connection"
+        } else {
+            "operation"
+        };
+        Error::internal("db", code).wrap_raw(err)
+    }
+}
+
+fn is_transient_pg_error(err: &PgError) -> bool {
+    match err.code() {
+        // connection_exception and its subclasses, plus pool/resource exhaustion.
+        Some(state) => state.code().starts_with("08") || state.code() == "53300",
+        // Errors without a SQLSTATE are connection-level only when the connection closed or
+        // an I/O error caused them; client-side failures (parameter conversion, row
+        // decoding) are not.
+        None => err.is_closed() || error::Error::source(err).map_or(false, |source| source.is::<std::io::Error>()),
     }
 }
 
@@ -348,6 +434,64 @@ mod tests {
         assert_eq!(err3.context().get("e2-key"), Some(&"value".to_owned()));
         assert_eq!(err3.context().get("e3-key"), Some(&"value".to_owned()));
     }
+
+    #[test]
+    fn to_response_json_collapses_internal_cause() {
+        let cause = Error::internal("db", "pool")
+            .set_message("connection refused")
+            .add_context("host", "localhost");
+        let err = Error::new("user", "not_found")
+            .set_status(404)
+            .set_message("user not found")
+            .wrap(cause);
+
+        let json = err.to_response_json(false);
+        assert_eq!(json["kind"], "application");
+        assert_eq!(json["path"], "user");
+        assert_eq!(json["code"], "not_found");
+        assert_eq!(json["status"], 404);
+        assert_eq!(json["message"], "user not found");
+
+        let causes = json["causes"].as_array().unwrap();
+        assert_eq!(causes.len(), 1);
+        assert_eq!(causes[0]["kind"], "internal");
+        assert_eq!(causes[0]["code"], "internal_error");
+        assert!(causes[0].get("message").is_none());
+    }
+
+    #[test]
+    fn retryable_distinguishes_pool_exhaustion_from_query_errors() {
+        assert!(Error::internal("db", "pool").retryable());
+        assert!(Error::internal("db", "connection").retryable());
+        assert!(Error::internal("redis", "pool").retryable());
+        assert!(!Error::internal("db", "operation").retryable());
+        assert!(!Error::new("user", "not_found").retryable());
+
+        let wrapped = Error::new("event", "create").wrap(Error::internal("db", "connection"));
+        assert!(wrapped.retryable());
+    }
+
+    #[test]
+    fn to_response_json_verbose_exposes_internal_cause() {
+        let cause = Error::internal("db", "pool").set_message("connection refused");
+        let err = Error::new("user", "not_found").wrap(cause);
+
+        let json = err.to_response_json(true);
+        let causes = json["causes"].as_array().unwrap();
+        assert_eq!(causes[0]["code"], "pool");
+        assert_eq!(causes[0]["message"], "connection refused");
+    }
+
+    #[test]
+    fn to_response_json_collapses_internal_outer_error() {
+        let err = Error::internal("db", "connection").set_message("connection reset");
+
+        let json = err.to_response_json(false);
+        assert_eq!(json["kind"], "internal");
+        assert_eq!(json["code"], "internal_error");
+        assert!(json.get("message").is_none());
+        assert!(json.get("path").is_none());
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
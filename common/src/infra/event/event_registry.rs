@@ -0,0 +1,128 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::result::Result;
+
+/// The payload of an event, either deserialized into the type registered for its
+/// `(topic, code)` pair, or left as raw JSON when no deserializer is registered.
+pub enum EventPayload<T> {
+    Typed(T),
+    Dynamic(Value),
+}
+
+type BoxedDeserializer = Arc<dyn Fn(Value) -> Result<Box<dyn Any + Send + Sync>> + Send + Sync>;
+
+/// A registry of `(topic, code) -> deserializer` mappings, letting producers/consumers
+/// get compile-time-checked event payloads without losing the ability to handle event
+/// types nobody has registered yet.
+#[derive(Default, Clone)]
+pub struct EventRegistry {
+    deserializers: HashMap<(String, String), BoxedDeserializer>,
+}
+
+impl EventRegistry {
+    pub fn new() -> Self {
+        EventRegistry {
+            deserializers: HashMap::new(),
+        }
+    }
+
+    pub fn register<T, S, F>(&mut self, topic: S, code: S, deserialize: F)
+    where
+        T: 'static + Send + Sync,
+        S: Into<String>,
+        F: Fn(Value) -> Result<T> + Send + Sync + 'static,
+    {
+        let key = (topic.into(), code.into());
+        self.deserializers.insert(
+            key,
+            Arc::new(move |value| deserialize(value).map(|t| Box::new(t) as Box<dyn Any + Send + Sync>)),
+        );
+    }
+
+    pub fn deserialize<T: 'static>(&self, topic: &str, code: &str, payload: Value) -> Result<EventPayload<T>> {
+        let key = (topic.to_owned(), code.to_owned());
+        match self.deserializers.get(&key) {
+            Some(deserialize) => {
+                let boxed = deserialize(payload).map_err(|err| {
+                    Error::bad_format("event")
+                        .add_context("topic", topic)
+                        .add_context("code", code)
+                        .wrap(err)
+                })?;
+                let typed = boxed.downcast::<T>().map_err(|_| {
+                    Error::bad_format("event")
+                        .add_context("topic", topic)
+                        .add_context("code", code)
+                })?;
+                Ok(EventPayload::Typed(*typed))
+            }
+            None => Ok(EventPayload::Dynamic(payload)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct SignupPayload {
+        email: String,
+    }
+
+    #[test]
+    fn registered_pair_deserializes_typed() {
+        let mut registry = EventRegistry::new();
+        registry.register("users", "signup", |value| {
+            serde_json::from_value::<SignupPayload>(value).map_err(Error::from)
+        });
+
+        let payload = serde_json::json!({ "email": "a@b.com" });
+        let result = registry
+            .deserialize::<SignupPayload>("users", "signup", payload)
+            .unwrap();
+
+        match result {
+            EventPayload::Typed(payload) => assert_eq!(payload.email, "a@b.com"),
+            EventPayload::Dynamic(_) => panic!("expected typed payload"),
+        }
+    }
+
+    #[test]
+    fn unregistered_pair_falls_back_to_dynamic() {
+        let registry = EventRegistry::new();
+        let payload = serde_json::json!({ "email": "a@b.com" });
+        let result = registry
+            .deserialize::<SignupPayload>("users", "signup", payload.clone())
+            .unwrap();
+
+        match result {
+            EventPayload::Typed(_) => panic!("expected dynamic payload"),
+            EventPayload::Dynamic(value) => assert_eq!(value, payload),
+        }
+    }
+
+    #[test]
+    fn malformed_payload_is_bad_format() {
+        let mut registry = EventRegistry::new();
+        registry.register("users", "signup", |value| {
+            serde_json::from_value::<SignupPayload>(value).map_err(Error::from)
+        });
+
+        let payload = serde_json::json!({ "not_email": true });
+        let err = registry
+            .deserialize::<SignupPayload>("users", "signup", payload)
+            .unwrap_err();
+
+        assert_eq!(err.code(), "bad_format");
+        assert_eq!(err.context().get("topic").unwrap(), "users");
+        assert_eq!(err.context().get("code").unwrap(), "signup");
+    }
+}
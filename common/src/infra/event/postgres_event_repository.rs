@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -11,14 +12,156 @@ use crate::event::{Event, EventId, EventRepositoryExt};
 use crate::result::Result;
 use crate::sql::where_builder::WhereBuilder;
 
+use super::event_registry::{EventPayload, EventRegistry};
+use super::redis_event_bus::EventBusExt;
+
 pub struct PostgresEventRepository {
     client: Arc<Client>,
+    event_bus: Option<Arc<dyn EventBusExt>>,
 }
 
 impl PostgresEventRepository {
     pub fn new(client: Arc<Client>) -> Self {
-        PostgresEventRepository { client }
+        PostgresEventRepository {
+            client,
+            event_bus: None,
+        }
     }
+
+    /// Publishes every saved event onto this bus in addition to persisting it.
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBusExt>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// Like [`search`](EventRepositoryExt::search), but runs each event's payload through
+    /// `registry` and returns a strongly-typed, validated payload where one is registered
+    /// for its `(topic, code)` pair, falling back to the raw JSON otherwise.
+    ///
+    /// `T` is a single, caller-chosen target type, so `topic`/`code` are mandatory here
+    /// (unlike [`search`](EventRepositoryExt::search)): every row has to come from the
+    /// same registered deserializer, or a row registered for a different type would fail
+    /// `downcast::<T>()` and abort the whole batch. Querying a heterogeneous mix of event
+    /// types calls for [`search`](EventRepositoryExt::search) plus a per-row
+    /// `registry.deserialize` keyed on each event's own `(topic, code)` instead.
+    pub async fn search_typed<T: 'static>(
+        &self,
+        registry: &EventRegistry,
+        topic: &str,
+        code: &str,
+        from: Option<&DateTime<Utc>>,
+        to: Option<&DateTime<Utc>>,
+    ) -> Result<Vec<EventPayload<T>>> {
+        let topic = topic.to_owned();
+        let code = code.to_owned();
+        let events = self.search(Some(&topic), Some(&code), from, to).await?;
+
+        events
+            .into_iter()
+            .map(|event| registry.deserialize(&event.topic(), &event.code(), event.payload().clone()))
+            .collect()
+    }
+
+    /// Keyset-paginated variant of [`search`](EventRepositoryExt::search). `cursor` is the
+    /// opaque `next_cursor` returned by a previous call (or `None` to start from the
+    /// beginning of the filtered range); pagination keys off the composite
+    /// `(timestamp, id)` so events sharing a timestamp are never skipped or duplicated
+    /// across page boundaries.
+    pub async fn search_page(
+        &self,
+        topic: Option<&String>,
+        code: Option<&String>,
+        from: Option<&DateTime<Utc>>,
+        to: Option<&DateTime<Utc>>,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Event>, Option<String>)> {
+        let decoded_cursor = cursor.map(decode_cursor).transpose()?;
+
+        let (mut sql, mut params) = WhereBuilder::new()
+            .add_param_opt("topic = $$", &topic, topic.is_some())
+            .add_param_opt("code = $$", &code, code.is_some())
+            .add_param_opt("timestamp >= $$", &from, from.is_some())
+            .add_param_opt("timestamp <= $$", &to, to.is_some())
+            .build();
+
+        if let Some((cursor_ts, cursor_id)) = &decoded_cursor {
+            let next = params.len() + 1;
+            let clause = format!("(timestamp, id) > (${}, ${})", next, next + 1);
+            sql = if sql.trim().is_empty() {
+                format!("WHERE {}", clause)
+            } else {
+                format!("{} AND {}", sql, clause)
+            };
+            params.push(cursor_ts);
+            params.push(cursor_id);
+        }
+
+        let page_size = (limit + 1) as i64;
+        let rows = self
+            .client
+            .query(
+                &format!(
+                    "SELECT * FROM events
+                    {}
+                    ORDER BY timestamp ASC, id ASC
+                    LIMIT {}",
+                    sql, page_size,
+                ) as &str,
+                &params,
+            )
+            .await
+            .map_err(|err| Error::not_found("event").wrap_raw(err))?;
+
+        let mut events = Vec::new();
+
+        for row in rows.into_iter() {
+            let id: Vec<u8> = row.get("id");
+            let topic: String = row.get("topic");
+            let code: String = row.get("code");
+            let timestamp: DateTime<Utc> = row.get("timestamp");
+            let payload: Value = row.get("payload");
+
+            events.push(Event::build(
+                EventId::from(id),
+                topic,
+                code,
+                timestamp,
+                payload,
+            ));
+        }
+
+        let next_cursor = if events.len() > limit {
+            events.truncate(limit);
+            events
+                .last()
+                .map(|event| encode_cursor(event.timestamp(), event.id()))
+        } else {
+            None
+        };
+
+        Ok((events, next_cursor))
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct EventCursor {
+    timestamp: DateTime<Utc>,
+    id: Vec<u8>,
+}
+
+fn encode_cursor(timestamp: DateTime<Utc>, id: &EventId) -> String {
+    let cursor = EventCursor {
+        timestamp,
+        id: id.as_ref().to_vec(),
+    };
+    base64::encode(serde_json::to_vec(&cursor).expect("EventCursor always serializes"))
+}
+
+fn decode_cursor(cursor: &str) -> Result<(DateTime<Utc>, Vec<u8>)> {
+    let bytes = base64::decode(cursor).map_err(|err| Error::bad_format("event.cursor").wrap_raw(err))?;
+    let cursor: EventCursor = serde_json::from_slice(&bytes)?;
+    Ok((cursor.timestamp, cursor.id))
 }
 
 #[async_trait]
@@ -73,31 +216,41 @@ impl EventRepositoryExt for PostgresEventRepository {
     }
 
     async fn save(&self, event: &Event) -> Result<()> {
-        self.client
-            .execute(
-                "INSERT INTO events (
-                    id,
-                    topic,
-                    code,
-                    timestamp,
-                    payload
-                ) VALUES (
-                    $1,
-                    $2,
-                    $3,
-                    $4,
-                    $5
-                )",
-                &[
-                    &event.id().as_ref(),
-                    &event.topic(),
-                    &event.code(),
-                    &event.timestamp(),
-                    &event.payload(),
-                ],
-            )
-            .await
-            .map_err(|err| Error::new("event", "create").wrap_raw(err))?;
+        crate::retry::retry_transient(3, Duration::from_millis(50), || async {
+            self.client
+                .execute(
+                    "INSERT INTO events (
+                        id,
+                        topic,
+                        code,
+                        timestamp,
+                        payload
+                    ) VALUES (
+                        $1,
+                        $2,
+                        $3,
+                        $4,
+                        $5
+                    )
+                    ON CONFLICT (id) DO NOTHING",
+                    &[
+                        &event.id().as_ref(),
+                        &event.topic(),
+                        &event.code(),
+                        &event.timestamp(),
+                        &event.payload(),
+                    ],
+                )
+                .await
+                .map_err(|err| Error::new("event", "create").wrap(Error::from(err)))
+        })
+        .await?;
+
+        if let Some(event_bus) = &self.event_bus {
+            if let Err(err) = event_bus.publish(event).await {
+                tracing::warn!("failed to publish event {}: {}", event.id(), err);
+            }
+        }
 
         Ok(())
     }
@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream, StreamExt};
+use tokio::sync::mpsc;
+
+use crate::event::{Event, EventRepositoryExt};
+use crate::result::Result;
+
+use super::postgres_event_repository::PostgresEventRepository;
+
+pub type EventStream = Pin<Box<dyn Stream<Item = Result<Event>> + Send>>;
+
+/// Turns an event repository from a passive store into a streaming source: producers
+/// publish onto a topic-keyed channel, consumers subscribe by topic and (optionally)
+/// replay everything since a given timestamp before switching over to the live feed.
+#[async_trait]
+pub trait EventBusExt: Send + Sync {
+    async fn publish(&self, event: &Event) -> Result<()>;
+
+    async fn subscribe(&self, topics: &[String], since: Option<DateTime<Utc>>) -> Result<EventStream>;
+}
+
+pub struct RedisEventBus {
+    pool: deadpool_redis::Pool,
+    events: Arc<PostgresEventRepository>,
+}
+
+impl RedisEventBus {
+    pub fn new(pool: deadpool_redis::Pool, events: Arc<PostgresEventRepository>) -> Self {
+        RedisEventBus { pool, events }
+    }
+
+    async fn replay(&self, topics: &[String], since: DateTime<Utc>) -> Result<Vec<Event>> {
+        let mut replayed = Vec::new();
+        for topic in topics {
+            let events = self
+                .events
+                .search(Some(topic), None, Some(&since), None)
+                .await?;
+            replayed.extend(events);
+        }
+        replayed.sort_by_key(|event| *event.timestamp());
+        Ok(replayed)
+    }
+}
+
+#[async_trait]
+impl EventBusExt for RedisEventBus {
+    async fn publish(&self, event: &Event) -> Result<()> {
+        let payload = serde_json::to_string(event)?;
+        let mut conn = self.pool.get().await?;
+        redis::cmd("PUBLISH")
+            .arg(event.topic())
+            .arg(payload)
+            .query_async::<_, ()>(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn subscribe(&self, topics: &[String], since: Option<DateTime<Utc>>) -> Result<EventStream> {
+        // Subscribe to the live feed *before* running the replay query, and buffer
+        // whatever arrives in between onto an unbounded channel, so an event published
+        // in that window is never lost (it would otherwise land in neither stream).
+        let conn = self.pool.get().await?;
+        let mut pubsub = conn.into_pubsub();
+        for topic in topics {
+            pubsub.subscribe(topic.as_str()).await?;
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut live = pubsub.into_on_message();
+            while let Some(msg) = live.next().await {
+                let event: Result<Event> = (|| {
+                    let payload: String = msg.get_payload()?;
+                    Ok(serde_json::from_str(&payload)?)
+                })();
+                if tx.send(event).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let replayed = match since {
+            Some(since) => self.replay(topics, since).await?,
+            None => Vec::new(),
+        };
+
+        // Everything published before (or during) the replay `SELECT` either shows up in
+        // `replayed` or lands on the channel (the live subscription was already open by
+        // then) — so de-dup any live event at or before the last replayed timestamp
+        // against this set. Past that point no more overlap is possible, so the live
+        // stream stops paying for the check.
+        let seen: HashSet<(DateTime<Utc>, Vec<u8>)> = replayed
+            .iter()
+            .map(|event| (*event.timestamp(), event.id().as_ref().to_vec()))
+            .collect();
+        let max_replayed_ts = replayed.last().map(|event| *event.timestamp());
+
+        let live = stream::unfold(
+            (rx, seen, max_replayed_ts),
+            |(mut rx, mut seen, max_replayed_ts)| async move {
+                loop {
+                    let event = rx.recv().await?;
+
+                    if let Ok(event) = &event {
+                        let within_seam = max_replayed_ts.map_or(false, |max| *event.timestamp() <= max);
+                        if within_seam {
+                            let key = (*event.timestamp(), event.id().as_ref().to_vec());
+                            if !seen.insert(key) {
+                                continue; // already delivered as part of the replay
+                            }
+                        }
+                    }
+
+                    return Some((event, (rx, seen, max_replayed_ts)));
+                }
+            },
+        );
+
+        let stream = stream::iter(replayed.into_iter().map(Ok)).chain(live);
+
+        Ok(Box::pin(stream))
+    }
+}
@@ -0,0 +1,116 @@
+use tokio_postgres::Client;
+
+use crate::error::Error;
+use crate::result::Result;
+
+/// A single forward-only schema change, applied at most once and tracked by `version`
+/// in the `schema_migrations` bookkeeping table.
+struct Migration {
+    version: i64,
+    up_sql: &'static str,
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    up_sql: "
+        CREATE TABLE events (
+            id BYTEA PRIMARY KEY,
+            topic TEXT NOT NULL,
+            code TEXT NOT NULL,
+            timestamp TIMESTAMPTZ NOT NULL,
+            payload JSONB NOT NULL
+        );
+        CREATE INDEX events_topic_code_idx ON events (topic, code);
+        CREATE INDEX events_timestamp_idx ON events (timestamp);
+    ",
+}];
+
+/// Advisory lock key guarding the migration check-and-apply step, so that several
+/// service instances starting at the same time don't race to apply the same migration.
+const MIGRATION_LOCK_KEY: i64 = 0x6576_656e_7473; // "events" in hex, just needs to be a stable constant
+
+/// Runs any migration in [`MIGRATIONS`] that hasn't been recorded in `schema_migrations`
+/// yet, in version order, each inside its own transaction. Takes `&mut Client` (rather
+/// than `&Client`) because `tokio_postgres::Client::transaction` requires exclusive
+/// access to open one.
+pub struct Migrator;
+
+impl Migrator {
+    pub async fn run(client: &mut Client) -> Result<()> {
+        Self::ensure_bookkeeping_table(client).await?;
+
+        for migration in MIGRATIONS {
+            Self::apply_if_missing(client, migration).await?;
+        }
+
+        Ok(())
+    }
+
+    // Takes the advisory lock for this too (and commits before the per-migration
+    // transactions start), so concurrent instances racing to create the bookkeeping
+    // table on first startup can't trip over each other's `IF NOT EXISTS` DDL.
+    async fn ensure_bookkeeping_table(client: &mut Client) -> Result<()> {
+        let tx = client
+            .transaction()
+            .await
+            .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?;
+
+        tx.execute("SELECT pg_advisory_xact_lock($1)", &[&MIGRATION_LOCK_KEY])
+            .await
+            .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?;
+
+        tx.batch_execute(
+            "CREATE TABLE IF NOT EXISTS schema_migrations (
+                version BIGINT PRIMARY KEY,
+                applied_at TIMESTAMPTZ NOT NULL DEFAULT now()
+            )",
+        )
+        .await
+        .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?;
+
+        tx.commit()
+            .await
+            .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?;
+
+        Ok(())
+    }
+
+    async fn apply_if_missing(client: &mut Client, migration: &Migration) -> Result<()> {
+        let tx = client
+            .transaction()
+            .await
+            .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?;
+
+        tx.execute("SELECT pg_advisory_xact_lock($1)", &[&MIGRATION_LOCK_KEY])
+            .await
+            .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?;
+
+        let already_applied = tx
+            .query_opt(
+                "SELECT 1 FROM schema_migrations WHERE version = $1",
+                &[&migration.version],
+            )
+            .await
+            .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?
+            .is_some();
+
+        if !already_applied {
+            tx.batch_execute(migration.up_sql)
+                .await
+                .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?;
+
+            tx.execute(
+                "INSERT INTO schema_migrations (version) VALUES ($1)",
+                &[&migration.version],
+            )
+            .await
+            .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|err| Error::internal("db", "migration").wrap_raw(err))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use std::future::Future;
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::result::Result;
+
+/// Re-runs `op` with exponential backoff (`base_delay * 2^n`, plus jitter) while it keeps
+/// failing with a [`retryable`](crate::error::Error::retryable) error and attempts remain,
+/// so a momentary pool/connection blip does not surface as a hard failure.
+pub async fn retry_transient<F, Fut, T>(attempts: usize, base_delay: Duration, mut op: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt + 1 < attempts && err.retryable() => {
+                let backoff = base_delay.saturating_mul(1u32 << attempt.min(16));
+                let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=backoff.as_millis() as u64 / 2 + 1));
+                tokio::time::sleep(backoff.saturating_add(jitter)).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crate::error::Error;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn succeeds_on_first_try_without_retrying() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+
+        let result = retry_transient(3, Duration::from_millis(1), move || {
+            let calls = calls_clone.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(42)
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn exhausts_attempts_on_a_persistently_transient_error() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+
+        let result: Result<()> = retry_transient(3, Duration::from_millis(1), move || {
+            let calls = calls_clone.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(Error::internal("db", "pool"))
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn non_retryable_error_returns_without_retrying() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+
+        let result: Result<()> = retry_transient(3, Duration::from_millis(1), move || {
+            let calls = calls_clone.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(Error::new("event", "create"))
+            }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}